@@ -1,8 +1,39 @@
+/// The error returned by [`try_hex()`] when a string cannot be decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromHexError {
+    /// The input string's length is not a multiple of 2.
+    OddLength,
+    /// The input's length does not match the expected output length (for
+    /// the fixed-size functions: the input string's length or number of hex
+    /// digits is not `N * 2`), or a provided output buffer is too small.
+    InvalidStringLength,
+    /// The input string contains a character other than `0-9`, `a-f` or
+    /// `A-F`, at byte offset `index`.
+    InvalidHexCharacter { c: char, index: usize },
+}
+
+impl core::fmt::Display for FromHexError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FromHexError::OddLength => write!(f, "Length needs to be even"),
+            FromHexError::InvalidStringLength => {
+                write!(f, "Invalid length (input and output lengths do not match).")
+            }
+            FromHexError::InvalidHexCharacter { c, index } => {
+                write!(f, "Invalid character '{c}' at index {index}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromHexError {}
+
 /// Turns a hex string into a vector of bytes.
-/// 
+///
 /// See also the `hex!()` macro, which wraps this function and automatically
 /// fills the correct value of the generic parameter `N`.
-/// 
+///
 /// ```
 /// # use myhex::hex;
 /// let bytes = hex("010aff");
@@ -11,71 +42,406 @@
 /// // with type annotations
 /// let bytes: [u8; 3] = hex::<3>("010AFF");
 /// assert_eq!(bytes, [1, 10, 255]);
-/// 
+///
 /// // usage as a constant
 /// const BYTES: [u8; 3] = hex("010AFf");
 /// assert_eq!(BYTES, [1, 10, 255]);
 /// ```
-/// 
+///
 /// Panics if the input string's length is not a multiple of 2, if the
-/// generic parameter `N` is not half of the input length or if it 
+/// generic parameter `N` is not half of the input length or if it
 /// contains characters other than `0-9`, `a-f` and `A-F`.
-/// 
+///
 /// ```should_panic
 /// # use myhex::hex;
 /// // invalid input length
 /// hex::<1>("111");
 /// ```
-/// 
+///
 /// ```should_panic
 /// # use myhex::hex;
 /// // generic parameter `N` is not half of the input size.
 /// hex::<3>("1111");
 /// ```
-/// 
+///
 /// ```should_panic
 /// # use myhex::hex;
 /// // input contains invalid character `"X"`
 /// hex::<2>("11X1");
 /// ```
-/// 
-/// When using `hex()` in a constant context, panics will become 
+///
+/// When using `hex()` in a constant context, panics will become
 /// compilation errors:
-/// 
+///
 /// ```compile_fail
 /// # use myhex::hex;
 /// // input contains invalid character `"X"`
 /// const X: [u8; 2] = hex("11X1");
 /// ```
+///
+/// For a non-panicking variant that can be used on untrusted, runtime-known
+/// input, see [`try_hex()`].
 pub const fn hex<const N: usize>(s: &str) -> [u8; N] {
+    match try_hex(s) {
+        Ok(arr) => arr,
+        Err(FromHexError::OddLength) => panic!("Length needs to be even"),
+        Err(FromHexError::InvalidStringLength) => {
+            panic!("Invalid length (`N * 2 == s.len()` not satisfied).")
+        }
+        Err(FromHexError::InvalidHexCharacter { .. }) => panic!("Invalid character"),
+    }
+}
+
+/// Turns a hex string into an array of bytes, returning a [`FromHexError`]
+/// instead of panicking on invalid input.
+///
+/// This is the fallible counterpart of [`hex()`], useful for decoding
+/// untrusted input whose validity isn't known at compile time (config
+/// files, network data, ...). `hex()` is implemented on top of this
+/// function.
+///
+/// ```
+/// # use myhex::{try_hex, FromHexError};
+/// let bytes: Result<[u8; 3], _> = try_hex("010aff");
+/// assert_eq!(bytes, Ok([1, 10, 255]));
+///
+/// assert_eq!(try_hex::<1>("111"), Err(FromHexError::OddLength));
+/// assert_eq!(try_hex::<3>("1111"), Err(FromHexError::InvalidStringLength));
+/// assert_eq!(
+///     try_hex::<2>("11X1"),
+///     Err(FromHexError::InvalidHexCharacter { c: 'X', index: 2 }),
+/// );
+/// ```
+pub const fn try_hex<const N: usize>(s: &str) -> Result<[u8; N], FromHexError> {
     let bytes = s.as_bytes();
 
-    assert!(bytes.len() % 2 == 0, "Length needs to be even");
-    assert!(bytes.len() == N * 2, "Invalid length (`N * 2 == s.len()` not satisfied).");
-    
+    if bytes.len() % 2 != 0 {
+        return Err(FromHexError::OddLength);
+    }
+    if bytes.len() != N * 2 {
+        return Err(FromHexError::InvalidStringLength);
+    }
+
     let mut arr = [0; N];
     let mut idx = 0;
     while idx < N {
-        let msb = ascii_char_to_num(bytes[idx * 2]);
-        let lsb = ascii_char_to_num(bytes[idx * 2 + 1]);
-        arr[idx] = (msb<<4) + lsb;
+        let msb = match checked_ascii_char_to_num(bytes[idx * 2]) {
+            Some(v) => v,
+            None => {
+                return Err(FromHexError::InvalidHexCharacter {
+                    c: bytes[idx * 2] as char,
+                    index: idx * 2,
+                })
+            }
+        };
+        let lsb = match checked_ascii_char_to_num(bytes[idx * 2 + 1]) {
+            Some(v) => v,
+            None => {
+                return Err(FromHexError::InvalidHexCharacter {
+                    c: bytes[idx * 2 + 1] as char,
+                    index: idx * 2 + 1,
+                })
+            }
+        };
+        arr[idx] = (msb << 4) + lsb;
+        idx += 1;
+    }
+    Ok(arr)
+}
+
+/// Turns a single ascii character into the number it represents, or `None`
+/// for characters other than `0-9`, `a-f` and `A-F`.
+const fn checked_ascii_char_to_num(ascii_char: u8) -> Option<u8> {
+    match ascii_char {
+        b'0'..=b'9' => Some(ascii_char - b'0'),
+        b'a'..=b'f' => Some(ascii_char - b'a' + 10),
+        b'A'..=b'F' => Some(ascii_char - b'A' + 10),
+        _ => None,
+    }
+}
+
+const LOWER_NIBBLES: &[u8; 16] = b"0123456789abcdef";
+const UPPER_NIBBLES: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Turns bytes into a hex string, using the given nibble table, at
+/// compile-time.
+const fn encode_with_table<const N: usize, const M: usize>(
+    bytes: &[u8; N],
+    table: &[u8; 16],
+) -> [u8; M] {
+    assert!(M == N * 2, "Invalid length (`M == N * 2` not satisfied).");
+
+    let mut arr = [0; M];
+    let mut idx = 0;
+    while idx < N {
+        let byte = bytes[idx];
+        arr[idx * 2] = table[(byte >> 4) as usize];
+        arr[idx * 2 + 1] = table[(byte & 0xf) as usize];
         idx += 1;
     }
     arr
 }
 
-/// Turns a single ascii character into the number it represents.
+/// Turns bytes into a lowercase hex string.
 ///
-/// Panics for characters other than `0-9`, `a-f` and `A-F`.
-const fn ascii_char_to_num(ascii_char: u8) -> u8 {
-    match ascii_char {
-        b'0'..=b'9' => ascii_char - b'0',
-        b'a'..=b'f' => ascii_char - b'a' + 10,
-        b'A'..=b'F' => ascii_char - b'A' + 10,
-        _ => panic!("Invalid character"),
+/// See also the `encode!()` macro, which wraps this function and
+/// automatically fills the correct value of the generic parameter `M`.
+///
+/// ```
+/// # use myhex::encode;
+/// let s: [u8; 6] = encode(&[1, 10, 255]);
+/// assert_eq!(&s, b"010aff");
+///
+/// // usage as a constant
+/// const S: [u8; 6] = encode(&[1, 10, 255]);
+/// assert_eq!(&S, b"010aff");
+/// ```
+///
+/// Panics if the generic parameter `M` is not twice the length of `bytes`.
+///
+/// ```should_panic
+/// # use myhex::encode;
+/// // generic parameter `M` is not twice the length of `bytes`.
+/// let _: [u8; 5] = encode(&[1, 10, 255]);
+/// ```
+pub const fn encode<const N: usize, const M: usize>(bytes: &[u8; N]) -> [u8; M] {
+    encode_with_table(bytes, LOWER_NIBBLES)
+}
+
+/// Turns bytes into an uppercase hex string.
+///
+/// See [`encode()`] for details; this only differs in using `A-F` instead of
+/// `a-f` for the digits above 9.
+///
+/// ```
+/// # use myhex::encode_upper;
+/// let s: [u8; 6] = encode_upper(&[1, 10, 255]);
+/// assert_eq!(&s, b"010AFF");
+/// ```
+pub const fn encode_upper<const N: usize, const M: usize>(bytes: &[u8; N]) -> [u8; M] {
+    encode_with_table(bytes, UPPER_NIBBLES)
+}
+
+/// Decodes `s` into the provided buffer `out`, without allocating.
+///
+/// Unlike [`hex()`] and [`try_hex()`], the output length doesn't need to be
+/// known at compile time: `out` only needs to be *at least* `s.len() / 2`
+/// bytes, which makes this suitable for decoding a runtime-known number of
+/// bytes into a reusable stack buffer. Returns the filled prefix of `out`.
+///
+/// ```
+/// # use myhex::decode_to_slice;
+/// let mut buf = [0u8; 8];
+/// let decoded = decode_to_slice("010aff", &mut buf).unwrap();
+/// assert_eq!(decoded, &[1, 10, 255]);
+/// ```
+///
+/// Returns [`FromHexError::OddLength`] if `s` has an odd length,
+/// [`FromHexError::InvalidStringLength`] if `out` is too small to hold the
+/// decoded bytes, or [`FromHexError::InvalidHexCharacter`] if `s` contains
+/// a character other than `0-9`, `a-f` or `A-F`.
+///
+/// ```
+/// # use myhex::{decode_to_slice, FromHexError};
+/// let mut buf = [0u8; 1];
+/// assert_eq!(decode_to_slice("010aff", &mut buf), Err(FromHexError::InvalidStringLength));
+/// ```
+pub fn decode_to_slice<'a>(s: &str, out: &'a mut [u8]) -> Result<&'a mut [u8], FromHexError> {
+    let bytes = s.as_bytes();
+    if bytes.len() % 2 != 0 {
+        return Err(FromHexError::OddLength);
+    }
+    let len = bytes.len() / 2;
+    if out.len() < len {
+        return Err(FromHexError::InvalidStringLength);
+    }
+
+    for idx in 0..len {
+        let msb = checked_ascii_char_to_num(bytes[idx * 2]).ok_or(FromHexError::InvalidHexCharacter {
+            c: bytes[idx * 2] as char,
+            index: idx * 2,
+        })?;
+        let lsb = checked_ascii_char_to_num(bytes[idx * 2 + 1]).ok_or(FromHexError::InvalidHexCharacter {
+            c: bytes[idx * 2 + 1] as char,
+            index: idx * 2 + 1,
+        })?;
+        out[idx] = (msb << 4) + lsb;
+    }
+    Ok(&mut out[..len])
+}
+
+/// Encodes `bytes` as a lowercase hex string into the provided buffer
+/// `out`, without allocating.
+///
+/// Unlike [`encode()`], the input length doesn't need to be known at
+/// compile time: `out` only needs to be *at least* `bytes.len() * 2` bytes,
+/// which makes this suitable for encoding a runtime-known number of bytes
+/// into a reusable stack buffer. Returns the filled prefix of `out`.
+///
+/// ```
+/// # use myhex::encode_to_slice;
+/// let mut buf = [0u8; 8];
+/// let encoded = encode_to_slice(&[1, 10, 255], &mut buf).unwrap();
+/// assert_eq!(encoded, "010aff");
+/// ```
+///
+/// Returns [`FromHexError::InvalidStringLength`] if `out` is too small to
+/// hold the encoded string.
+///
+/// ```
+/// # use myhex::{encode_to_slice, FromHexError};
+/// let mut buf = [0u8; 1];
+/// assert_eq!(encode_to_slice(&[1, 10, 255], &mut buf), Err(FromHexError::InvalidStringLength));
+/// ```
+pub fn encode_to_slice<'a>(bytes: &[u8], out: &'a mut [u8]) -> Result<&'a str, FromHexError> {
+    let len = bytes.len() * 2;
+    if out.len() < len {
+        return Err(FromHexError::InvalidStringLength);
+    }
+
+    for (idx, byte) in bytes.iter().enumerate() {
+        out[idx * 2] = LOWER_NIBBLES[(byte >> 4) as usize];
+        out[idx * 2 + 1] = LOWER_NIBBLES[(byte & 0xf) as usize];
+    }
+    Ok(core::str::from_utf8(&out[..len]).expect("hex digits are valid utf8"))
+}
+
+/// Turns a single hex digit character into the number it represents, or
+/// `None` for characters other than `0-9`, `a-f` and `A-F`.
+///
+/// Unlike [`checked_ascii_char_to_num()`], this works on a `char` directly
+/// rather than a single-byte ascii value, which [`try_hex_relaxed()`] needs
+/// since it walks the input's `char_indices()` to skip separators.
+fn checked_hex_char_to_num(c: char) -> Option<u8> {
+    match c {
+        '0'..='9' => Some(c as u8 - b'0'),
+        'a'..='f' => Some(c as u8 - b'a' + 10),
+        'A'..='F' => Some(c as u8 - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Whether `c` is a separator that [`hex_relaxed()`] and
+/// [`try_hex_relaxed()`] ignore between byte pairs.
+fn is_hex_separator(c: char) -> bool {
+    c.is_ascii_whitespace() || matches!(c, ':' | '-' | '_')
+}
+
+/// Turns a hex string into an array of bytes, tolerating a leading `0x`/`0X`
+/// prefix and separators (ascii whitespace, `:`, `-`, `_`) between byte
+/// pairs.
+///
+/// Real-world hex shows up in hexdumps, MAC addresses and fingerprints,
+/// none of which `hex()` accepts directly. This is a runtime (non-const)
+/// function, since stripping a variable number of separators can't be done
+/// at compile time the way `hex()`'s fixed indexing is.
+///
+/// ```
+/// # use myhex::hex_relaxed;
+/// assert_eq!(hex_relaxed::<3>("0x01 0a-ff"), [1, 10, 255]);
+/// assert_eq!(hex_relaxed::<4>("de:ad:be:ef"), [0xde, 0xad, 0xbe, 0xef]);
+/// ```
+///
+/// Still panics on a trailing half-byte or an invalid character, same as
+/// [`hex()`].
+///
+/// ```should_panic
+/// # use myhex::hex_relaxed;
+/// hex_relaxed::<2>("de:ad:b");
+/// ```
+pub fn hex_relaxed<const N: usize>(s: &str) -> [u8; N] {
+    match try_hex_relaxed(s) {
+        Ok(arr) => arr,
+        Err(FromHexError::OddLength) => panic!("Length needs to be even"),
+        Err(FromHexError::InvalidStringLength) => {
+            panic!("Invalid length (number of hex digits is not `N * 2`).")
+        }
+        Err(FromHexError::InvalidHexCharacter { .. }) => panic!("Invalid character"),
     }
 }
 
+/// Turns a hex string into an array of bytes, returning a [`FromHexError`]
+/// instead of panicking on invalid input.
+///
+/// See [`hex_relaxed()`] for the accepted input format.
+///
+/// ```
+/// # use myhex::{try_hex_relaxed, FromHexError};
+/// assert_eq!(try_hex_relaxed::<3>("0x01 0a-ff"), Ok([1, 10, 255]));
+/// assert_eq!(try_hex_relaxed::<2>("de:ad:b"), Err(FromHexError::OddLength));
+/// assert_eq!(
+///     try_hex_relaxed::<2>("de:aX"),
+///     Err(FromHexError::InvalidHexCharacter { c: 'X', index: 4 }),
+/// );
+/// ```
+pub fn try_hex_relaxed<const N: usize>(s: &str) -> Result<[u8; N], FromHexError> {
+    let digits = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")).unwrap_or(s);
+    let prefix_len = s.len() - digits.len();
+
+    let mut arr = [0u8; N];
+    let mut out_idx = 0;
+    let mut high: Option<u8> = None;
+    for (index, c) in digits.char_indices() {
+        if is_hex_separator(c) {
+            continue;
+        }
+        let val = checked_hex_char_to_num(c).ok_or(FromHexError::InvalidHexCharacter {
+            c,
+            index: prefix_len + index,
+        })?;
+        match high.take() {
+            None => high = Some(val),
+            Some(h) => {
+                if out_idx >= N {
+                    return Err(FromHexError::InvalidStringLength);
+                }
+                arr[out_idx] = (h << 4) + val;
+                out_idx += 1;
+            }
+        }
+    }
+    if high.is_some() {
+        return Err(FromHexError::OddLength);
+    }
+    if out_idx != N {
+        return Err(FromHexError::InvalidStringLength);
+    }
+    Ok(arr)
+}
+
+/// Turns bytes into a hex string at compile-time.
+///
+/// Compared to using the `encode()` function directly, this macro ensures
+/// that the transformation is evaluated at compile time, even when the
+/// result is used in a regular (non-const) variable. This macro also makes
+/// sure that the generic parameter `M` of `encode()` is set correctly.
+///
+/// ```rust
+/// # use myhex::encode;
+/// assert_eq!(&myhex::encode!([1, 10, 255]), b"010aff");
+///
+/// // declaring a constant
+/// myhex::encode! {
+///     const MY_HEX = [0x12, 0x34, 0x56];
+/// }
+/// assert_eq!(&MY_HEX, b"123456");
+///
+/// // declaring a variable (evaluation still happens at compile-time)
+/// let my_hex = myhex::encode!([0x12, 0x34, 0x56]);
+/// assert_eq!(&my_hex, b"123456");
+/// ```
+#[macro_export]
+macro_rules! encode {
+    (const $name:ident = $bytes:expr;) => {
+        const $name: [u8; $bytes.len() * 2] = $crate::encode(&$bytes);
+    };
+    ($bytes:expr) => {{
+        const BYTES: &[u8] = &$bytes;
+        const X: [u8; { BYTES.len() * 2 }] = $crate::encode(&$bytes);
+        X
+    }};
+}
+
 /// Turns a hex string into an of bytes at compile-time.
 /// 
 /// Compared to to using the `hex()` function directly, this macro ensures 
@@ -124,12 +490,249 @@ macro_rules! hex {
     };
 }
 
+/// Decodes a single ascii hex character into its nibble value, or a
+/// negative value if `ascii_char` is not `0-9`, `a-f` or `A-F`.
+///
+/// The computation is branchless and never indexes a table by `ascii_char`,
+/// so its timing does not depend on the *value* of the character - only
+/// functions built from this primitive (like [`hex_ct()`]) are suitable for
+/// decoding secret key material.
+const fn ct_ascii_to_nibble(ascii_char: u8) -> i16 {
+    let b = ascii_char as i16;
+    let mut ret: i16 = -1;
+    ret += (((0x2f - b) & (b - 0x3a)) >> 8) & (b - 47);
+    ret += (((0x40 - b) & (b - 0x47)) >> 8) & (b - 54);
+    ret += (((0x60 - b) & (b - 0x67)) >> 8) & (b - 86);
+    ret
+}
+
+/// Encodes a single nibble (`0..=15`) into its lowercase ascii hex
+/// character, without indexing a lookup table by `nibble`.
+const fn ct_nibble_to_ascii(nibble: u8) -> u8 {
+    let mut ret = nibble as i16 + 0x30;
+    ret += ((0x39 - ret) >> 8) & (0x61 - 0x3a);
+    ret as u8
+}
+
+/// Turns a hex string into an array of bytes, without branching or
+/// table-indexing on the decoded data.
+///
+/// This is intended for decoding secret key material, where `hex()`'s
+/// per-character range matching and early-exit branches could leak information about the
+/// input through timing or cache side channels. It is constant-time with
+/// respect to the *content* of `s`, but not with respect to its *length* -
+/// the length-related panics below still happen before any data is
+/// touched.
+///
+/// ```
+/// # use myhex::hex_ct;
+/// let bytes = hex_ct("010aff");
+/// assert_eq!(bytes, [1, 10, 255]);
+/// ```
+///
+/// Panics if the input string's length is not a multiple of 2, if the
+/// generic parameter `N` is not half of the input length or if it
+/// contains characters other than `0-9`, `a-f` and `A-F`.
+///
+/// ```should_panic
+/// # use myhex::hex_ct;
+/// // input contains invalid character `"X"`
+/// hex_ct::<2>("11X1");
+/// ```
+pub const fn hex_ct<const N: usize>(s: &str) -> [u8; N] {
+    let bytes = s.as_bytes();
+
+    assert!(bytes.len() % 2 == 0, "Length needs to be even");
+    assert!(
+        bytes.len() == N * 2,
+        "Invalid length (`N * 2 == s.len()` not satisfied)."
+    );
+
+    let mut arr = [0; N];
+    let mut idx = 0;
+    let mut err = 0i16;
+    while idx < N {
+        let msb = ct_ascii_to_nibble(bytes[idx * 2]);
+        let lsb = ct_ascii_to_nibble(bytes[idx * 2 + 1]);
+        err |= msb | lsb;
+        arr[idx] = ((msb as u8) << 4).wrapping_add(lsb as u8);
+        idx += 1;
+    }
+    assert!(err >= 0, "Invalid character");
+    arr
+}
+
+/// Turns bytes into a lowercase hex string, without table-indexing on the
+/// data being encoded.
+///
+/// See [`hex_ct()`] for why this matters when handling secret key
+/// material.
+///
+/// ```
+/// # use myhex::encode_ct;
+/// let s: [u8; 6] = encode_ct(&[1, 10, 255]);
+/// assert_eq!(&s, b"010aff");
+/// ```
+pub const fn encode_ct<const N: usize, const M: usize>(bytes: &[u8; N]) -> [u8; M] {
+    assert!(M == N * 2, "Invalid length (`M == N * 2` not satisfied).");
+
+    let mut arr = [0; M];
+    let mut idx = 0;
+    while idx < N {
+        let byte = bytes[idx];
+        arr[idx * 2] = ct_nibble_to_ascii(byte >> 4);
+        arr[idx * 2 + 1] = ct_nibble_to_ascii(byte & 0xf);
+        idx += 1;
+    }
+    arr
+}
+
+/// Types that the [`serde`] helpers can decode a hex string or raw byte
+/// slice into.
+#[cfg(feature = "serde")]
+pub trait FromHexBytes: Sized {
+    /// Decodes a hex string, as produced on human-readable formats.
+    fn from_hex_str(hex: &str) -> Result<Self, FromHexError>;
+    /// Adopts already-decoded bytes, as produced on binary formats.
+    fn from_raw_bytes(bytes: &[u8]) -> Result<Self, FromHexError>;
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> FromHexBytes for [u8; N] {
+    fn from_hex_str(hex: &str) -> Result<Self, FromHexError> {
+        try_hex(hex)
+    }
+
+    fn from_raw_bytes(bytes: &[u8]) -> Result<Self, FromHexError> {
+        bytes.try_into().map_err(|_| FromHexError::InvalidStringLength)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl FromHexBytes for std::vec::Vec<u8> {
+    fn from_hex_str(hex: &str) -> Result<Self, FromHexError> {
+        let bytes = hex.as_bytes();
+        if bytes.len() % 2 != 0 {
+            return Err(FromHexError::OddLength);
+        }
+        let mut out = std::vec::Vec::with_capacity(bytes.len() / 2);
+        let mut idx = 0;
+        while idx < bytes.len() {
+            let msb = checked_ascii_char_to_num(bytes[idx]).ok_or(FromHexError::InvalidHexCharacter {
+                c: bytes[idx] as char,
+                index: idx,
+            })?;
+            let lsb = checked_ascii_char_to_num(bytes[idx + 1]).ok_or(FromHexError::InvalidHexCharacter {
+                c: bytes[idx + 1] as char,
+                index: idx + 1,
+            })?;
+            out.push((msb << 4) + lsb);
+            idx += 2;
+        }
+        Ok(out)
+    }
+
+    fn from_raw_bytes(bytes: &[u8]) -> Result<Self, FromHexError> {
+        Ok(bytes.to_vec())
+    }
+}
+
+/// Serde (de)serialization helpers for hex-encoded byte fields.
+///
+/// Use with `#[serde(with = "myhex::serde")]` on `[u8; N]` or `Vec<u8>`
+/// fields to serialize them as a lowercase hex string on human-readable
+/// formats (JSON, TOML, ...), or as raw bytes on binary formats.
+///
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Key {
+///     #[serde(with = "myhex::serde")]
+///     bytes: [u8; 4],
+/// }
+///
+/// let key = Key { bytes: [0xde, 0xad, 0xbe, 0xef] };
+/// let json = serde_json::to_string(&key).unwrap();
+/// assert_eq!(json, r#"{"bytes":"deadbeef"}"#);
+/// assert_eq!(serde_json::from_str::<Key>(&json).unwrap().bytes, key.bytes);
+/// # }
+/// ```
+#[cfg(feature = "serde")]
+pub mod serde {
+    use super::{encode_to_slice, FromHexBytes};
+    use core::marker::PhantomData;
+    use serde::de::Visitor;
+    use serde::{Deserializer, Serializer};
+
+    /// Serializes `bytes` as a lowercase hex string on human-readable
+    /// formats, or as raw bytes otherwise.
+    pub fn serialize<T, S>(bytes: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AsRef<[u8]>,
+        S: Serializer,
+    {
+        let bytes = bytes.as_ref();
+        if serializer.is_human_readable() {
+            let mut buf = std::vec![0u8; bytes.len() * 2];
+            let s = encode_to_slice(bytes, &mut buf).expect("buf is exactly large enough");
+            serializer.serialize_str(s)
+        } else {
+            serializer.serialize_bytes(bytes)
+        }
+    }
+
+    struct HexVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: FromHexBytes> Visitor<'de> for HexVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "a hex string or raw bytes")
+        }
+
+        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            T::from_hex_str(v).map_err(E::custom)
+        }
+
+        fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            T::from_raw_bytes(v).map_err(E::custom)
+        }
+    }
+
+    /// Deserializes a value of type `T` (`[u8; N]` or `Vec<u8>`) from a hex
+    /// string on human-readable formats, or raw bytes otherwise.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromHexBytes,
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(HexVisitor(PhantomData))
+        } else {
+            deserializer.deserialize_bytes(HexVisitor(PhantomData))
+        }
+    }
+}
+
 
 #[test]
 fn test_ascii_char_to_num() {
-    assert_eq!(ascii_char_to_num(b'0'), 0);
-    assert_eq!(ascii_char_to_num(b'a'), 10);
-    assert_eq!(ascii_char_to_num(b'F'), 15);
+    assert_eq!(checked_ascii_char_to_num(b'0'), Some(0));
+    assert_eq!(checked_ascii_char_to_num(b'a'), Some(10));
+    assert_eq!(checked_ascii_char_to_num(b'F'), Some(15));
+    assert_eq!(checked_ascii_char_to_num(b'X'), None);
+}
+
+
+#[test]
+fn test_try_hex() {
+    assert_eq!(try_hex::<3>("010aff"), Ok([1, 10, 255]));
+    assert_eq!(try_hex::<1>("111"), Err(FromHexError::OddLength));
+    assert_eq!(try_hex::<3>("1111"), Err(FromHexError::InvalidStringLength));
+    assert_eq!(
+        try_hex::<2>("11X1"),
+        Err(FromHexError::InvalidHexCharacter { c: 'X', index: 2 }),
+    );
 }
 
 
@@ -143,3 +746,119 @@ fn test_macro() {
     // mixed-case letters
     assert_eq!(&hex!("AbcD"), &[0xab, 0xcd]);
 }
+
+
+#[test]
+fn test_encode() {
+    let s: [u8; 6] = encode(&[1, 10, 255]);
+    assert_eq!(&s, b"010aff");
+}
+
+#[test]
+fn test_encode_upper() {
+    let s: [u8; 6] = encode_upper(&[1, 10, 255]);
+    assert_eq!(&s, b"010AFF");
+}
+
+#[test]
+fn test_encode_macro() {
+    assert_eq!(&encode!([1, 10, 255]), b"010aff");
+}
+
+
+#[test]
+fn test_decode_to_slice() {
+    let mut buf = [0u8; 8];
+    assert_eq!(decode_to_slice("010aff", &mut buf).unwrap(), &[1, 10, 255]);
+
+    let mut buf = [0u8; 1];
+    assert_eq!(
+        decode_to_slice("010aff", &mut buf),
+        Err(FromHexError::InvalidStringLength)
+    );
+
+    let mut buf = [0u8; 8];
+    assert_eq!(decode_to_slice("111", &mut buf), Err(FromHexError::OddLength));
+    assert_eq!(
+        decode_to_slice("11X1", &mut buf),
+        Err(FromHexError::InvalidHexCharacter { c: 'X', index: 2 }),
+    );
+}
+
+#[test]
+fn test_encode_to_slice() {
+    let mut buf = [0u8; 8];
+    assert_eq!(encode_to_slice(&[1, 10, 255], &mut buf).unwrap(), "010aff");
+
+    let mut buf = [0u8; 1];
+    assert_eq!(
+        encode_to_slice(&[1, 10, 255], &mut buf),
+        Err(FromHexError::InvalidStringLength)
+    );
+}
+
+
+#[test]
+fn test_hex_relaxed() {
+    assert_eq!(hex_relaxed::<3>("0x01 0a-ff"), [1, 10, 255]);
+    assert_eq!(hex_relaxed::<4>("de:ad:be:ef"), [0xde, 0xad, 0xbe, 0xef]);
+    // no prefix, no separators - behaves like `hex()`
+    assert_eq!(hex_relaxed::<3>("010aff"), [1, 10, 255]);
+}
+
+#[test]
+fn test_try_hex_relaxed() {
+    assert_eq!(try_hex_relaxed::<3>("0x01 0a-ff"), Ok([1, 10, 255]));
+    assert_eq!(try_hex_relaxed::<2>("de:ad:b"), Err(FromHexError::OddLength));
+    assert_eq!(
+        try_hex_relaxed::<2>("de:aX"),
+        Err(FromHexError::InvalidHexCharacter { c: 'X', index: 4 }),
+    );
+    assert_eq!(
+        try_hex_relaxed::<3>("de:ad"),
+        Err(FromHexError::InvalidStringLength)
+    );
+}
+
+
+#[test]
+fn test_hex_ct() {
+    assert_eq!(hex_ct::<3>("010aff"), [1, 10, 255]);
+    assert_eq!(hex_ct::<2>("ABCD"), [0xab, 0xcd]);
+}
+
+#[test]
+#[should_panic]
+fn test_hex_ct_invalid_char() {
+    hex_ct::<2>("11X1");
+}
+
+#[test]
+#[should_panic(expected = "Invalid character")]
+fn test_hex_ct_invalid_low_nibble() {
+    // invalid character in the low-nibble position, with a non-zero high
+    // nibble - regression test for overflow in the branchless combine step.
+    hex_ct::<1>("1X");
+}
+
+#[test]
+fn test_encode_ct() {
+    let s: [u8; 6] = encode_ct(&[1, 10, 255]);
+    assert_eq!(&s, b"010aff");
+}
+
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    struct Key {
+        #[serde(with = "crate::serde")]
+        bytes: [u8; 4],
+    }
+
+    let key = Key { bytes: [0xde, 0xad, 0xbe, 0xef] };
+    let json = serde_json::to_string(&key).unwrap();
+    assert_eq!(json, r#"{"bytes":"deadbeef"}"#);
+    assert_eq!(serde_json::from_str::<Key>(&json).unwrap().bytes, key.bytes);
+}